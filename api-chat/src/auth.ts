@@ -17,6 +17,17 @@ interface AccessTokenPayload extends JwtPayload {
   sub: string;
 }
 
+/**
+ * ユーザーが無効化されているか (GitHub組織から外れた等) を確認する
+ * @param conn - MariaDBのコネクション
+ * @param userId - 確認するユーザーID
+ * @returns 無効化されていればtrue
+ */
+export const isUserDeactivated = async (conn: mariadb.PoolConnection, userId: number): Promise<boolean> => {
+  const rows = await conn.query('SELECT deactivated_at FROM users WHERE id = ?', [userId]);
+  return rows.length > 0 && rows[0].deactivated_at !== null;
+};
+
 /**
  * 認証ミドルウェアを生成するファクトリー関数
  * @param db - MariaDBのプール
@@ -72,6 +83,12 @@ export const createAuthenticateTokenMiddleware = (db: mariadb.Pool) => {
           userId.toString(),
           `User${userId}`
         ]);
+
+        // 無効化されたユーザーはトークンが有効期限内でも拒否する
+        if (await isUserDeactivated(conn, userId)) {
+          console.warn(`Rejected request from deactivated user ${userId}`);
+          return res.status(403).send('Forbidden: This account has been deactivated.');
+        }
       } catch (e) {
         console.error('Failed to upsert user:', e);
         return res.status(500).send('Internal Server Error');
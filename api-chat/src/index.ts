@@ -9,6 +9,7 @@ import cookieParser from 'cookie-parser';
 import { createChatRouter } from './routes';
 import jwt from 'jsonwebtoken';
 import { onJoin, onMessage, onDisconnect } from './socketHandlers';
+import { isUserDeactivated } from './auth';
 
 dotenv.config();
 
@@ -93,12 +94,33 @@ io.use((socket, next) => {
     return next(new Error('Internal server configuration error.'));
   }
 
-  jwt.verify(token, jwtSecret, (err, decoded) => {
+  jwt.verify(token, jwtSecret, async (err, decoded) => {
     if (err) {
       console.warn(`Token verification failed for socket ${socket.id}: ${err.message}`);
       return next(new Error('Authentication error: Invalid token'));
     }
-    (socket as AuthenticatedSocket).data.user = decoded as { sub: string; exp: number; };
+    const user = decoded as { sub: string; exp: number; };
+    const userId = parseInt(user.sub, 10);
+    if (isNaN(userId)) {
+      return next(new Error('Authentication error: Invalid user ID in token'));
+    }
+
+    // 無効化されたユーザーは接続させない
+    let conn;
+    try {
+      conn = await db.getConnection();
+      if (await isUserDeactivated(conn, userId)) {
+        console.warn(`Rejected socket ${socket.id} from deactivated user ${userId}`);
+        return next(new Error('Authentication error: Account deactivated'));
+      }
+    } catch (e) {
+      console.error('Failed to check user status:', e);
+      return next(new Error('Internal server error.'));
+    } finally {
+      conn?.release();
+    }
+
+    (socket as AuthenticatedSocket).data.user = user;
     next();
   });
 });
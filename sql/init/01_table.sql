@@ -6,6 +6,14 @@ CREATE TABLE IF NOT EXISTS `users` (
     `username` VARCHAR(255) NOT NULL UNIQUE,
     `display_name` VARCHAR(255) NOT NULL,
     `avatar_url` TEXT,
+    `email` VARCHAR(255), -- GitHubのプライマリメールアドレス (検証状態は email_verified)
+    `email_verified` BOOLEAN NOT NULL DEFAULT FALSE,
+    -- GitHubの GET /user から取得できる任意項目 (未設定ならNULL)
+    `name` VARCHAR(255),
+    `company` VARCHAR(255),
+    `bio` TEXT,
+    `role` VARCHAR(32) NOT NULL DEFAULT 'user', -- 既定のロール。user_rolesに行があるユーザーはそちらが優先される
+    `deactivated_at` DATETIME, -- GitHub組織から外れた等で無効化された日時 (NULLなら有効)
     `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
     `updated_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
 );
@@ -14,12 +22,77 @@ CREATE TABLE IF NOT EXISTS `users` (
 CREATE TABLE IF NOT EXISTS `refresh_tokens` (
     `jti` VARCHAR(255) PRIMARY KEY,
     `user_id` BIGINT NOT NULL,
+    `family_id` CHAR(36),        -- ログイン時に採番し、ローテーションで引き継ぐトークンファミリーのUUID
+    `parent_jti` VARCHAR(255),   -- ローテーション元のjti (ログイン直後の最初のトークンはNULL)
+    `rotated_at` DATETIME,       -- ローテーションされた日時 (未ローテーションならNULL)
+    `successor_jti` VARCHAR(255), -- ローテーションで発行された後継トークンのjti
+    `session_started_at` DATETIME, -- セッションの最初のログイン日時 (ローテーション後も同じ値)
+    `remember_me` BOOLEAN NOT NULL DEFAULT TRUE, -- ログイン状態の保持を選んだか (FALSEなら有効期限の短いトークン)
     `expires_at` DATETIME NOT NULL,
+    `created_at` DATETIME DEFAULT CURRENT_TIMESTAMP, -- 発行日時 (記録を始める前に発行された行はNULL)
+    `user_agent` TEXT,           -- セッションのログイン時のUser-Agent (ローテーションでは変わらない)
+    `user_agent_hash` CHAR(64),  -- 正規化したUser-Agent (ブラウザ種別+メジャーバージョン+OS) のSHA-256
+    `ip_address` VARCHAR(45),    -- セッションのログイン時のクライアントIP (IPv6を考慮して45文字)
+    `issued_subnet` VARCHAR(64), -- 発行元のサブネット (IPv4は/24, IPv6は/48)
+    `last_used_at` DATETIME,     -- 最後にローテーションで使われた日時
+    `last_ip` VARCHAR(45),       -- 最後にローテーションで使われたときのクライアントIP
     UNIQUE KEY (`user_id`),
     FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
 );
 CREATE INDEX `idx_refresh_tokens_user_id` ON `refresh_tokens`(`user_id`);
 CREATE INDEX `idx_refresh_tokens_expires_at` ON `refresh_tokens`(`expires_at`);
+-- 再利用検知時に同じファミリーのトークンだけを削除するため
+CREATE INDEX `idx_refresh_tokens_family_id` ON `refresh_tokens`(`family_id`);
+
+-- ========= OAuth state (認証サービス専用) =========
+-- ログインCSRF対策のstateと、それに紐づくリダイレクト先
+CREATE TABLE IF NOT EXISTS `oauth_states` (
+    `state` VARCHAR(255) PRIMARY KEY,
+    `redirect_to` TEXT,
+    `code_challenge` VARCHAR(128),       -- クライアントが送ったPKCEのチャレンジ (送られなかった場合はNULL)
+    `code_challenge_method` VARCHAR(10), -- チャレンジの方式 ('S256')
+    `code_verifier` VARCHAR(128), -- サーバー側で生成したPKCEのverifier (PKCEを使わないならNULL)
+    `expires_at` DATETIME NOT NULL,
+    `consumed_at` DATETIME, -- 使用済みになった日時 (未使用ならNULL)
+    `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+-- 期限切れのstateを削除するため
+CREATE INDEX `idx_oauth_states_expires_at` ON `oauth_states`(`expires_at`);
+
+-- ========= ユーザーロール (認証サービス専用) =========
+-- GitHubのチームメンバーシップから解決したロール。行があるユーザーは users.role より優先される
+CREATE TABLE IF NOT EXISTS `user_roles` (
+    `user_id` BIGINT NOT NULL,
+    `role` VARCHAR(32) NOT NULL,
+    `granted_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (`user_id`, `role`),
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
+
+-- ========= GitHubトークン (認証サービス専用) =========
+-- GitHub Appのユーザートークンとそのリフレッシュトークン。どちらもアプリ側で暗号化して格納する
+CREATE TABLE IF NOT EXISTS `github_tokens` (
+    `user_id` BIGINT PRIMARY KEY,
+    `access_token_encrypted` VARBINARY(1024) NOT NULL,
+    `access_token_expires_at` DATETIME,
+    `refresh_token_encrypted` VARBINARY(1024),
+    `refresh_token_expires_at` DATETIME,
+    `needs_reauth` BOOLEAN NOT NULL DEFAULT FALSE, -- 上流のリフレッシュに失敗した場合にTRUE
+    `updated_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
+
+-- ========= アクセストークン (認証サービス専用) =========
+-- 不透明なアクセストークン。トークン本体は保存せず、SHA-256ハッシュのみを格納する
+CREATE TABLE IF NOT EXISTS `access_tokens` (
+    `token_hash` CHAR(64) PRIMARY KEY,
+    `user_id` BIGINT NOT NULL,
+    `expires_at` DATETIME NOT NULL,
+    `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
+CREATE INDEX `idx_access_tokens_user_id` ON `access_tokens`(`user_id`);
+CREATE INDEX `idx_access_tokens_expires_at` ON `access_tokens`(`expires_at`);
 
 -- ========= サーバー (チャットサービス専用) =========
 CREATE TABLE IF NOT EXISTS `chat_servers` (
@@ -0,0 +1,27 @@
+#!/bin/bash
+# sql/migrations/*.sql を番号順に db コンテナへ適用する
+# 使い方: sql/migrate.sh [docker-compose の -f オプション...]
+#   例: sql/migrate.sh -f docker-compose.yml -f docker-compose.prod.yml
+# パスワードとデータベース名は、ホストではなくコンテナ内の環境変数から読む
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+COMPOSE=(docker-compose "$@")
+
+# 初期化中の一時サーバーはTCPを受け付けないため、TCPで応答するまで待つ
+# (新しいボリュームでは sql/init の実行が終わってから適用される)
+for i in $(seq 1 30); do
+  if "${COMPOSE[@]}" exec -T db sh -c 'mariadb-admin ping -h 127.0.0.1 --protocol=tcp -u root -p"$MYSQL_ROOT_PASSWORD" --silent' > /dev/null 2>&1; then
+    break
+  fi
+  if [ "$i" -eq 30 ]; then
+    echo "Database did not become ready."
+    exit 1
+  fi
+  sleep 2
+done
+
+for f in sql/migrations/*.sql; do
+  echo "Applying ${f}..."
+  "${COMPOSE[@]}" exec -T db sh -c 'exec mariadb -h 127.0.0.1 --protocol=tcp -u root -p"$MYSQL_ROOT_PASSWORD" "$MYSQL_DATABASE"' < "$f"
+done
@@ -0,0 +1,6 @@
+-- refresh_tokens に発行日時を追加
+-- 既存の行は発行日時が分からないためNULLのままにし、以降に発行される行だけ CURRENT_TIMESTAMP で埋める
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `created_at` DATETIME NULL AFTER `expires_at`;
+ALTER TABLE `refresh_tokens`
+    MODIFY COLUMN `created_at` DATETIME NULL DEFAULT CURRENT_TIMESTAMP;
@@ -0,0 +1,5 @@
+-- GitHubの GET /user から取得できる任意項目を users に追加
+ALTER TABLE `users`
+    ADD COLUMN IF NOT EXISTS `name` VARCHAR(255) AFTER `avatar_url`,
+    ADD COLUMN IF NOT EXISTS `company` VARCHAR(255) AFTER `name`,
+    ADD COLUMN IF NOT EXISTS `bio` TEXT AFTER `company`;
@@ -0,0 +1,3 @@
+-- users にメールアドレスを追加
+ALTER TABLE `users`
+    ADD COLUMN IF NOT EXISTS `email` VARCHAR(255) AFTER `avatar_url`;
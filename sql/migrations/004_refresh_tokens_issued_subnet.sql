@@ -0,0 +1,3 @@
+-- refresh_tokens に発行元サブネットを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `issued_subnet` VARCHAR(64) AFTER `created_at`;
@@ -0,0 +1,4 @@
+-- refresh_tokens にUser-AgentとクライアントIPを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `user_agent` TEXT AFTER `created_at`,
+    ADD COLUMN IF NOT EXISTS `ip_address` VARCHAR(45) AFTER `user_agent`;
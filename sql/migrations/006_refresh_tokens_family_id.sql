@@ -0,0 +1,4 @@
+-- refresh_tokens にトークンファミリーを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `family_id` CHAR(36) AFTER `user_id`;
+CREATE INDEX IF NOT EXISTS `idx_refresh_tokens_family_id` ON `refresh_tokens`(`family_id`);
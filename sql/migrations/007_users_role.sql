@@ -0,0 +1,3 @@
+-- users にロールを追加 (既存の行は 'user' になる)
+ALTER TABLE `users`
+    ADD COLUMN IF NOT EXISTS `role` VARCHAR(32) NOT NULL DEFAULT 'user' AFTER `bio`;
@@ -0,0 +1,9 @@
+-- ログインCSRF対策のstateと、それに紐づくリダイレクト先
+CREATE TABLE IF NOT EXISTS `oauth_states` (
+    `state` VARCHAR(255) PRIMARY KEY,
+    `redirect_to` TEXT,
+    `expires_at` DATETIME NOT NULL,
+    `consumed_at` DATETIME,
+    `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS `idx_oauth_states_expires_at` ON `oauth_states`(`expires_at`);
@@ -0,0 +1,3 @@
+-- oauth_states にPKCEのverifierを追加
+ALTER TABLE `oauth_states`
+    ADD COLUMN IF NOT EXISTS `code_verifier` VARCHAR(128) AFTER `redirect_to`;
@@ -0,0 +1,3 @@
+-- users にメールアドレスの検証状態を追加
+ALTER TABLE `users`
+    ADD COLUMN IF NOT EXISTS `email_verified` BOOLEAN NOT NULL DEFAULT FALSE AFTER `email`;
@@ -0,0 +1,8 @@
+-- GitHubのチームメンバーシップから解決したロール
+CREATE TABLE IF NOT EXISTS `user_roles` (
+    `user_id` BIGINT NOT NULL,
+    `role` VARCHAR(32) NOT NULL,
+    `granted_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (`user_id`, `role`),
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
@@ -0,0 +1,11 @@
+-- GitHub Appのユーザートークン (暗号化して格納)
+CREATE TABLE IF NOT EXISTS `github_tokens` (
+    `user_id` BIGINT PRIMARY KEY,
+    `access_token_encrypted` VARBINARY(1024) NOT NULL,
+    `access_token_expires_at` DATETIME,
+    `refresh_token_encrypted` VARBINARY(1024),
+    `refresh_token_expires_at` DATETIME,
+    `needs_reauth` BOOLEAN NOT NULL DEFAULT FALSE,
+    `updated_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- users に無効化日時を追加 (NULLなら有効)
+ALTER TABLE `users`
+    ADD COLUMN IF NOT EXISTS `deactivated_at` DATETIME AFTER `role`;
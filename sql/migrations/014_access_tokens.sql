@@ -0,0 +1,10 @@
+-- 不透明なアクセストークン (SHA-256ハッシュのみを格納)
+CREATE TABLE IF NOT EXISTS `access_tokens` (
+    `token_hash` CHAR(64) PRIMARY KEY,
+    `user_id` BIGINT NOT NULL,
+    `expires_at` DATETIME NOT NULL,
+    `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (`user_id`) REFERENCES `users`(`id`) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS `idx_access_tokens_user_id` ON `access_tokens`(`user_id`);
+CREATE INDEX IF NOT EXISTS `idx_access_tokens_expires_at` ON `access_tokens`(`expires_at`);
@@ -0,0 +1,3 @@
+-- refresh_tokens にローテーション元のjtiを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `parent_jti` VARCHAR(255) AFTER `family_id`;
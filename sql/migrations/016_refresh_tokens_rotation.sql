@@ -0,0 +1,4 @@
+-- refresh_tokens にローテーション日時と後継トークンのjtiを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `rotated_at` DATETIME AFTER `parent_jti`,
+    ADD COLUMN IF NOT EXISTS `successor_jti` VARCHAR(255) AFTER `rotated_at`;
@@ -0,0 +1,3 @@
+-- refresh_tokens にセッションの最初のログイン日時を追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `session_started_at` DATETIME AFTER `successor_jti`;
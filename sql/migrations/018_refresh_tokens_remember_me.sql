@@ -0,0 +1,3 @@
+-- refresh_tokens に remember_me を追加 (既存の行は従来どおり長期間有効)
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `remember_me` BOOLEAN NOT NULL DEFAULT TRUE AFTER `session_started_at`;
@@ -0,0 +1,4 @@
+-- refresh_tokens に最終利用日時とIPを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `last_used_at` DATETIME AFTER `issued_subnet`,
+    ADD COLUMN IF NOT EXISTS `last_ip` VARCHAR(45) AFTER `last_used_at`;
@@ -0,0 +1,3 @@
+-- refresh_tokens に正規化したUser-Agentのハッシュを追加
+ALTER TABLE `refresh_tokens`
+    ADD COLUMN IF NOT EXISTS `user_agent_hash` CHAR(64) AFTER `user_agent`;
@@ -0,0 +1,4 @@
+-- oauth_states にクライアントが送ったPKCEのチャレンジを追加
+ALTER TABLE `oauth_states`
+    ADD COLUMN IF NOT EXISTS `code_challenge` VARCHAR(128) AFTER `redirect_to`,
+    ADD COLUMN IF NOT EXISTS `code_challenge_method` VARCHAR(10) AFTER `code_challenge`;